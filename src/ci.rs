@@ -0,0 +1,188 @@
+// CI workflow generation for scaffolded projects. `CiProvider` only has a
+// `Github` variant today, but it exists (rather than a bare bool flag) so
+// `--ci` has somewhere to grow without changing every call site.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::package_manager::PackageManager;
+use crate::ProjectConfig;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lowercase")]
+pub enum CiProvider {
+    Github,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectKind {
+    React,
+    ReactNative,
+    Rust,
+    Tauri,
+}
+
+/// Write a CI workflow for `kind` into `project_path`, tailored to the
+/// provider recorded on `config.ci`. No-op if `config.ci` is `None`.
+pub fn generate_ci_workflow(project_path: &Path, config: &ProjectConfig, kind: ProjectKind) -> Result<()> {
+    let Some(provider) = config.ci else {
+        return Ok(());
+    };
+
+    match provider {
+        CiProvider::Github => generate_github_workflow(project_path, config, kind),
+    }
+}
+
+fn generate_github_workflow(project_path: &Path, config: &ProjectConfig, kind: ProjectKind) -> Result<()> {
+    let workflows_dir = project_path.join(".github/workflows");
+    fs::create_dir_all(&workflows_dir)?;
+
+    let yaml = match kind {
+        ProjectKind::React => react_workflow(config),
+        ProjectKind::ReactNative => react_native_workflow(config),
+        ProjectKind::Rust => rust_workflow(),
+        ProjectKind::Tauri => tauri_workflow(config.package_manager),
+    };
+
+    fs::write(workflows_dir.join("ci.yml"), yaml)?;
+    Ok(())
+}
+
+fn react_workflow(config: &ProjectConfig) -> String {
+    let pm = config.package_manager;
+    let test_step = if config.testing {
+        format!("      - run: {}\n", pm.run_command("test"))
+    } else {
+        String::new()
+    };
+
+    format!(
+        r#"name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-node@v4
+        with:
+          node-version: '20'
+          cache: '{cache}'
+      - run: {install}
+      - run: {lint}
+{test_step}      - run: {build}
+"#,
+        cache = pm.as_str(),
+        install = pm.install_command(),
+        lint = pm.run_command("lint"),
+        build = pm.run_command("build"),
+    )
+}
+
+fn react_native_workflow(config: &ProjectConfig) -> String {
+    let pm = config.package_manager;
+    let test_step = if config.testing {
+        format!("      - run: {}\n", pm.run_command("test"))
+    } else {
+        String::new()
+    };
+
+    format!(
+        r#"name: CI
+
+# The scaffolded project has no `android/` or `ios/` directory (no Gradle
+# wrapper, no Xcode workspace), so this workflow only covers the JS side.
+# It has no Android or iOS build job.
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  lint:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-node@v4
+        with:
+          node-version: '20'
+          cache: '{cache}'
+      - run: {install}
+      - run: {lint}
+{test_step}"#,
+        cache = pm.as_str(),
+        install = pm.install_command(),
+        lint = pm.run_command("lint"),
+    )
+}
+
+fn tauri_workflow(pm: PackageManager) -> String {
+    format!(
+        r#"name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-node@v4
+        with:
+          node-version: '20'
+          cache: '{cache}'
+      - uses: dtolnay/rust-toolchain@stable
+      - name: Install system dependencies
+        run: |
+          sudo apt-get update
+          sudo apt-get install -y libgtk-3-dev libwebkit2gtk-4.1-dev libayatana-appindicator3-dev librsvg2-dev
+      - run: {install}
+      - name: Test Tauri backend
+        working-directory: src-tauri
+        run: cargo test
+      - run: {build}
+"#,
+        cache = pm.as_str(),
+        install = pm.install_command(),
+        build = pm.run_command("build"),
+    )
+}
+
+fn rust_workflow() -> String {
+    r#"name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    strategy:
+      matrix:
+        rust: [stable, beta]
+    steps:
+      - uses: actions/checkout@v4
+      - uses: dtolnay/rust-toolchain@master
+        with:
+          toolchain: ${{ matrix.rust }}
+      - run: cargo build --verbose
+      - run: cargo test --verbose
+"#
+    .to_string()
+}
@@ -6,96 +6,247 @@
 // tokio = { version = "1.0", features = ["full"] }
 // anyhow = "1.0"
 // handlebars = "4.0"
+// dialoguer = "0.11"
+// toml = "0.8"
+
+mod ci;
+mod package_manager;
+mod project_file;
+mod template_engine;
+mod wizard;
 
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 use anyhow::{Result, Context};
-use handlebars::Handlebars;
-use std::collections::HashMap;
+use ci::CiProvider;
+use package_manager::PackageManager;
+use template_engine::TemplateEngine;
 
 #[derive(Parser)]
 #[command(name = "scaffold")]
 #[command(about = "A CLI tool for scaffolding React/React Native projects")]
 struct Cli {
+    /// Omit the subcommand (or its project name) to be walked through an
+    /// interactive wizard instead.
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Create a new React project
     React {
-        /// Project name
-        name: String,
+        /// Project name (omit to launch the interactive wizard)
+        name: Option<String>,
         /// Use TypeScript
         #[arg(short, long)]
         typescript: bool,
         /// Include testing setup
         #[arg(short = 'T', long)]
         testing: bool,
+        /// Package manager to install dependencies with
+        #[arg(long, value_enum, default_value_t = PackageManager::Npm)]
+        package_manager: PackageManager,
+        /// Add Storybook with a sample story
+        #[arg(long)]
+        storybook: bool,
+        /// Add Tailwind CSS
+        #[arg(long)]
+        tailwind: bool,
+        /// Add Playwright end-to-end tests
+        #[arg(long)]
+        e2e: bool,
+        /// Generate a CI workflow
+        #[arg(long, value_enum)]
+        ci: Option<CiProvider>,
     },
     /// Create a new React Native project
     ReactNative {
-        /// Project name
-        name: String,
+        /// Project name (omit to launch the interactive wizard)
+        name: Option<String>,
         /// Use TypeScript
         #[arg(short, long)]
         typescript: bool,
         /// Include navigation setup
         #[arg(short, long)]
         navigation: bool,
+        /// Package manager to install dependencies with
+        #[arg(long, value_enum, default_value_t = PackageManager::Npm)]
+        package_manager: PackageManager,
+        /// Generate a CI workflow
+        #[arg(long, value_enum)]
+        ci: Option<CiProvider>,
     },
     /// Create a new Rust project
     Rust {
-        /// Project name
-        name: String,
+        /// Project name (omit to launch the interactive wizard)
+        name: Option<String>,
         /// Project type
         #[arg(short, long, default_value = "binary")]
         project_type: String,
+        /// Generate a CI workflow
+        #[arg(long, value_enum)]
+        ci: Option<CiProvider>,
+    },
+    /// Create a new Tauri desktop project (Rust + React)
+    Tauri {
+        /// Project name (omit to launch the interactive wizard)
+        name: Option<String>,
+        /// Use TypeScript for the frontend
+        #[arg(short, long)]
+        typescript: bool,
+        /// Package manager to install frontend dependencies with
+        #[arg(long, value_enum, default_value_t = PackageManager::Npm)]
+        package_manager: PackageManager,
+        /// Generate a CI workflow
+        #[arg(long, value_enum)]
+        ci: Option<CiProvider>,
+    },
+    /// Add a feature to an already-scaffolded project
+    Add {
+        /// Feature to enable
+        feature: project_file::Feature,
+        /// Path to the scaffolded project
+        #[arg(long, default_value = ".")]
+        path: String,
+    },
+    /// Re-render a scaffolded project's missing files from its saved config
+    Regenerate {
+        /// Path to the scaffolded project
+        #[arg(long, default_value = ".")]
+        path: String,
     },
     /// List available templates
     List,
 }
 
 #[derive(Serialize, Deserialize)]
-struct ProjectConfig {
-    name: String,
-    typescript: bool,
-    testing: bool,
-    navigation: bool,
-}
-
-struct TemplateEngine {
-    handlebars: Handlebars<'static>,
-}
-
-impl TemplateEngine {
-    fn new() -> Self {
-        let mut handlebars = Handlebars::new();
-        // Register templates here
-        Self { handlebars }
-    }
-
-    fn render_template(&self, template: &str, data: &ProjectConfig) -> Result<String> {
-        self.handlebars.render_template(template, data)
-            .context("Failed to render template")
-    }
+pub struct ProjectConfig {
+    pub(crate) name: String,
+    pub(crate) framework: ci::ProjectKind,
+    pub(crate) typescript: bool,
+    pub(crate) testing: bool,
+    pub(crate) navigation: bool,
+    #[serde(default)]
+    pub(crate) package_manager: PackageManager,
+    #[serde(default)]
+    pub(crate) storybook: bool,
+    #[serde(default)]
+    pub(crate) tailwind: bool,
+    #[serde(default)]
+    pub(crate) e2e: bool,
+    #[serde(default)]
+    pub(crate) ci: Option<CiProvider>,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    match cli.command {
-        Commands::React { name, typescript, testing } => {
-            create_react_project(&name, typescript, testing)?;
+    let command = match cli.command {
+        Some(command) => command,
+        None => match wizard::project_type_wizard()? {
+            wizard::ProjectChoice::React => {
+                let answers = wizard::react_wizard()?;
+                Commands::React {
+                    name: Some(answers.name),
+                    typescript: answers.typescript,
+                    testing: answers.testing,
+                    package_manager: answers.package_manager,
+                    storybook: answers.storybook,
+                    tailwind: answers.tailwind,
+                    e2e: answers.e2e,
+                    ci: answers.ci,
+                }
+            }
+            wizard::ProjectChoice::ReactNative => {
+                let answers = wizard::react_native_wizard()?;
+                Commands::ReactNative {
+                    name: Some(answers.name),
+                    typescript: answers.typescript,
+                    navigation: answers.navigation,
+                    package_manager: answers.package_manager,
+                    ci: answers.ci,
+                }
+            }
+            wizard::ProjectChoice::Rust => {
+                let answers = wizard::rust_wizard()?;
+                Commands::Rust {
+                    name: Some(answers.name),
+                    project_type: answers.project_type,
+                    ci: answers.ci,
+                }
+            }
+            wizard::ProjectChoice::Tauri => {
+                let answers = wizard::tauri_wizard()?;
+                Commands::Tauri {
+                    name: Some(answers.name),
+                    typescript: answers.typescript,
+                    package_manager: answers.package_manager,
+                    ci: answers.ci,
+                }
+            }
+        },
+    };
+
+    match command {
+        Commands::React { name, typescript, testing, package_manager, storybook, tailwind, e2e, ci } => {
+            let (name, typescript, testing, package_manager, storybook, tailwind, e2e, ci) = match name {
+                Some(name) => (name, typescript, testing, package_manager, storybook, tailwind, e2e, ci),
+                None => {
+                    let answers = wizard::react_wizard()?;
+                    (
+                        answers.name,
+                        answers.typescript,
+                        answers.testing,
+                        answers.package_manager,
+                        answers.storybook,
+                        answers.tailwind,
+                        answers.e2e,
+                        answers.ci,
+                    )
+                }
+            };
+            create_react_project(&name, typescript, testing, package_manager, storybook, tailwind, e2e, ci)?;
+        }
+        Commands::ReactNative { name, typescript, navigation, package_manager, ci } => {
+            let (name, typescript, navigation, package_manager, ci) = match name {
+                Some(name) => (name, typescript, navigation, package_manager, ci),
+                None => {
+                    let answers = wizard::react_native_wizard()?;
+                    (answers.name, answers.typescript, answers.navigation, answers.package_manager, answers.ci)
+                }
+            };
+            create_react_native_project(&name, typescript, navigation, package_manager, ci)?;
+        }
+        Commands::Rust { name, project_type, ci } => {
+            let (name, project_type, ci) = match name {
+                Some(name) => (name, project_type, ci),
+                None => {
+                    let answers = wizard::rust_wizard()?;
+                    (answers.name, answers.project_type, answers.ci)
+                }
+            };
+            create_rust_project(&name, &project_type, ci)?;
+        }
+        Commands::Tauri { name, typescript, package_manager, ci } => {
+            let (name, typescript, package_manager, ci) = match name {
+                Some(name) => (name, typescript, package_manager, ci),
+                None => {
+                    let answers = wizard::tauri_wizard()?;
+                    (answers.name, answers.typescript, answers.package_manager, answers.ci)
+                }
+            };
+            create_tauri_project(&name, typescript, package_manager, ci)?;
         }
-        Commands::ReactNative { name, typescript, navigation } => {
-            create_react_native_project(&name, typescript, navigation)?;
+        Commands::Add { feature, path } => {
+            project_file::add_feature(Path::new(&path), feature)?;
+            println!("✅ Added {:?} to the project at {}", feature, path);
         }
-        Commands::Rust { name, project_type } => {
-            create_rust_project(&name, &project_type)?;
+        Commands::Regenerate { path } => {
+            project_file::regenerate(Path::new(&path))?;
+            println!("✅ Regenerated missing files for the project at {}", path);
         }
         Commands::List => {
             list_templates();
@@ -105,75 +256,106 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-fn create_react_project(name: &str, typescript: bool, testing: bool) -> Result<()> {
+fn create_react_project(
+    name: &str,
+    typescript: bool,
+    testing: bool,
+    package_manager: PackageManager,
+    storybook: bool,
+    tailwind: bool,
+    e2e: bool,
+    ci_provider: Option<CiProvider>,
+) -> Result<()> {
     println!("🚀 Creating React project: {}", name);
-    
+
     let project_path = Path::new(name);
-    
+
     if project_path.exists() {
         anyhow::bail!("Directory {} already exists", name);
     }
 
-    // Create project structure
-    create_directory_structure(project_path, &get_react_structure())?;
-    
-    // Generate package.json
-    let package_json = generate_package_json(name, typescript, testing, false)?;
-    fs::write(project_path.join("package.json"), package_json)?;
-    
-    // Generate tsconfig if TypeScript
-    if typescript {
-        let tsconfig = generate_tsconfig()?;
-        fs::write(project_path.join("tsconfig.json"), tsconfig)?;
-    }
-    
-    // Generate main files
-    generate_react_files(project_path, typescript)?;
-    
+    let config = ProjectConfig {
+        name: name.to_string(),
+        framework: ci::ProjectKind::React,
+        typescript,
+        testing,
+        navigation: false,
+        package_manager,
+        storybook,
+        tailwind,
+        e2e,
+        ci: ci_provider,
+    };
+
+    let engine = TemplateEngine::new("react")?;
+    let context = template_engine::build_context(&config)?;
+    engine.render_all(project_path, &context)?;
+    ci::generate_ci_workflow(project_path, &config, ci::ProjectKind::React)?;
+    project_file::save(project_path, &config)?;
+
     println!("✅ React project '{}' created successfully!", name);
     println!("📁 Next steps:");
     println!("   cd {}", name);
-    println!("   npm install");
-    println!("   npm start");
-    
+    println!("   {}", package_manager.install_command());
+    println!("   {}", package_manager.run_command("dev"));
+    if storybook {
+        println!("   {}  # launch Storybook", package_manager.run_command("storybook"));
+    }
+    if e2e {
+        println!("   {}  # run Playwright tests", package_manager.run_command("test:e2e"));
+    }
+
     Ok(())
 }
 
-fn create_react_native_project(name: &str, typescript: bool, navigation: bool) -> Result<()> {
+fn create_react_native_project(
+    name: &str,
+    typescript: bool,
+    navigation: bool,
+    package_manager: PackageManager,
+    ci_provider: Option<CiProvider>,
+) -> Result<()> {
     println!("📱 Creating React Native project: {}", name);
-    
+
     let project_path = Path::new(name);
-    
+
     if project_path.exists() {
         anyhow::bail!("Directory {} already exists", name);
     }
 
-    create_directory_structure(project_path, &get_react_native_structure())?;
-    
-    let package_json = generate_package_json(name, typescript, false, navigation)?;
-    fs::write(project_path.join("package.json"), package_json)?;
-    
-    if typescript {
-        let tsconfig = generate_tsconfig()?;
-        fs::write(project_path.join("tsconfig.json"), tsconfig)?;
-    }
-    
-    generate_react_native_files(project_path, typescript, navigation)?;
-    
+    let config = ProjectConfig {
+        name: name.to_string(),
+        framework: ci::ProjectKind::ReactNative,
+        typescript,
+        testing: false,
+        navigation,
+        package_manager,
+        storybook: false,
+        tailwind: false,
+        e2e: false,
+        ci: ci_provider,
+    };
+
+    let engine = TemplateEngine::new("react-native")?;
+    let context = template_engine::build_context(&config)?;
+    engine.render_all(project_path, &context)?;
+    ci::generate_ci_workflow(project_path, &config, ci::ProjectKind::ReactNative)?;
+    project_file::save(project_path, &config)?;
+
     println!("✅ React Native project '{}' created successfully!", name);
     println!("📁 Next steps:");
     println!("   cd {}", name);
-    println!("   npm install");
-    println!("   npx react-native run-android  # or run-ios");
-    
+    println!("   {}", package_manager.install_command());
+    println!("   {}  # or run-ios", package_manager.exec_command("react-native run-android"));
+
     Ok(())
 }
 
-fn create_rust_project(name: &str, project_type: &str) -> Result<()> {
+fn create_rust_project(name: &str, project_type: &str, ci_provider: Option<CiProvider>) -> Result<()> {
     println!("🦀 Creating Rust project: {}", name);
-    
+
     let project_path = Path::new(name);
-    
+
     if project_path.exists() {
         anyhow::bail!("Directory {} already exists", name);
     }
@@ -188,10 +370,25 @@ fn create_rust_project(name: &str, project_type: &str) -> Result<()> {
     if !output.status.success() {
         anyhow::bail!("Cargo new failed: {}", String::from_utf8_lossy(&output.stderr));
     }
-    
+
     // Add custom Cargo.toml configurations
     enhance_cargo_toml(project_path)?;
-    
+
+    let config = ProjectConfig {
+        name: name.to_string(),
+        framework: ci::ProjectKind::Rust,
+        typescript: false,
+        testing: false,
+        navigation: false,
+        package_manager: PackageManager::default(),
+        storybook: false,
+        tailwind: false,
+        e2e: false,
+        ci: ci_provider,
+    };
+    ci::generate_ci_workflow(project_path, &config, ci::ProjectKind::Rust)?;
+    project_file::save(project_path, &config)?;
+
     println!("✅ Rust project '{}' created successfully!", name);
     println!("📁 Next steps:");
     println!("   cd {}", name);
@@ -200,257 +397,51 @@ fn create_rust_project(name: &str, project_type: &str) -> Result<()> {
     Ok(())
 }
 
-fn create_directory_structure(base_path: &Path, structure: &[&str]) -> Result<()> {
-    fs::create_dir_all(base_path)?;
-    
-    for dir in structure {
-        let dir_path = base_path.join(dir);
-        fs::create_dir_all(dir_path)?;
-    }
-    
-    Ok(())
-}
-
-fn get_react_structure() -> Vec<&'static str> {
-    vec![
-        "src",
-        "src/components",
-        "src/hooks",
-        "src/utils",
-        "src/types",
-        "public",
-        ".vscode",
-    ]
-}
+fn create_tauri_project(
+    name: &str,
+    typescript: bool,
+    package_manager: PackageManager,
+    ci_provider: Option<CiProvider>,
+) -> Result<()> {
+    println!("🖥️  Creating Tauri project: {}", name);
 
-fn get_react_native_structure() -> Vec<&'static str> {
-    vec![
-        "src",
-        "src/components",
-        "src/screens",
-        "src/navigation",
-        "src/hooks",
-        "src/utils",
-        "src/types",
-        "android",
-        "ios",
-        ".vscode",
-    ]
-}
+    let project_path = Path::new(name);
 
-fn generate_package_json(name: &str, typescript: bool, testing: bool, navigation: bool) -> Result<String> {
-    let mut dependencies = vec![
-        ("react", "^18.2.0"),
-    ];
-    
-    let mut dev_dependencies = vec![
-        ("@vitejs/plugin-react", "^4.0.3"),
-        ("vite", "^4.4.5"),
-    ];
-    
-    if typescript {
-        dev_dependencies.extend_from_slice(&[
-            ("typescript", "^5.0.2"),
-            ("@types/react", "^18.2.15"),
-            ("@types/react-dom", "^18.2.7"),
-        ]);
-    }
-    
-    if testing {
-        dev_dependencies.extend_from_slice(&[
-            ("@testing-library/react", "^13.4.0"),
-            ("@testing-library/jest-dom", "^5.17.0"),
-            ("vitest", "^0.34.4"),
-        ]);
-    }
-    
-    if navigation {
-        dependencies.extend_from_slice(&[
-            ("@react-navigation/native", "^6.1.7"),
-            ("@react-navigation/stack", "^6.3.17"),
-        ]);
+    if project_path.exists() {
+        anyhow::bail!("Directory {} already exists", name);
     }
-    
-    // Build JSON string (simplified - in real implementation use serde_json)
-    let deps_str = dependencies.iter()
-        .map(|(name, version)| format!(r#"    "{}": "{}""#, name, version))
-        .collect::<Vec<_>>()
-        .join(",\n");
-        
-    let dev_deps_str = dev_dependencies.iter()
-        .map(|(name, version)| format!(r#"    "{}": "{}""#, name, version))
-        .collect::<Vec<_>>()
-        .join(",\n");
-    
-    Ok(format!(r#"{{
-  "name": "{}",
-  "private": true,
-  "version": "0.0.0",
-  "type": "module",
-  "scripts": {{
-    "dev": "vite",
-    "build": "vite build",
-    "lint": "eslint . --ext ts,tsx --report-unused-disable-directives --max-warnings 0",
-    "preview": "vite preview"{}
-  }},
-  "dependencies": {{
-{}
-  }},
-  "devDependencies": {{
-{}
-  }}
-}}"#, 
-        name,
-        if testing { r#",
-    "test": "vitest""# } else { "" },
-        deps_str,
-        dev_deps_str
-    ))
-}
-
-fn generate_tsconfig() -> Result<String> {
-    Ok(r#"{
-  "compilerOptions": {
-    "target": "ES2020",
-    "useDefineForClassFields": true,
-    "lib": ["ES2020", "DOM", "DOM.Iterable"],
-    "module": "ESNext",
-    "skipLibCheck": true,
-    "moduleResolution": "bundler",
-    "allowImportingTsExtensions": true,
-    "resolveJsonModule": true,
-    "isolatedModules": true,
-    "noEmit": true,
-    "jsx": "react-jsx",
-    "strict": true,
-    "noUnusedLocals": true,
-    "noUnusedParameters": true,
-    "noFallthroughCasesInSwitch": true
-  },
-  "include": ["src"],
-  "references": [{ "path": "./tsconfig.node.json" }]
-}"#.to_string())
-}
-
-fn generate_react_files(project_path: &Path, typescript: bool) -> Result<()> {
-    let ext = if typescript { "tsx" } else { "jsx" };
-    
-    let app_content = format!(r#"import React from 'react';
-import './App.css';
-
-function App() {{
-  return (
-    <div className="App">
-      <header className="App-header">
-        <h1>Welcome to your new React project!</h1>
-        <p>Edit src/App.{} and save to reload.</p>
-      </header>
-    </div>
-  );
-}}
-
-export default App;"#, ext);
-    
-    fs::write(project_path.join(format!("src/App.{}", ext)), app_content)?;
-    
-    let main_content = format!(r#"import React from 'react';
-import ReactDOM from 'react-dom/client';
-import App from './App.{}';
-import './index.css';
-
-ReactDOM.createRoot(document.getElementById('root')!).render(
-  <React.StrictMode>
-    <App />
-  </React.StrictMode>,
-);"#, ext);
-    
-    fs::write(project_path.join(format!("src/main.{}", if typescript { "tsx" } else { "jsx" })), main_content)?;
-    
-    // Basic CSS
-    let css_content = r#"body {
-  margin: 0;
-  font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', 'Roboto', 'Oxygen',
-    'Ubuntu', 'Cantarell', 'Fira Sans', 'Droid Sans', 'Helvetica Neue',
-    sans-serif;
-  -webkit-font-smoothing: antialiased;
-  -moz-osx-font-smoothing: grayscale;
-}
-
-.App {
-  text-align: center;
-}
 
-.App-header {
-  background-color: #282c34;
-  padding: 20px;
-  color: white;
-  min-height: 100vh;
-  display: flex;
-  flex-direction: column;
-  align-items: center;
-  justify-content: center;
-  font-size: calc(10px + 2vmin);
-}"#;
-    
-    fs::write(project_path.join("src/App.css"), css_content)?;
-    fs::write(project_path.join("src/index.css"), "/* Global styles */")?;
-    
-    Ok(())
-}
+    let config = ProjectConfig {
+        name: name.to_string(),
+        framework: ci::ProjectKind::Tauri,
+        typescript,
+        testing: false,
+        navigation: false,
+        package_manager,
+        storybook: false,
+        tailwind: false,
+        e2e: false,
+        ci: ci_provider,
+    };
+
+    // The frontend is just a React/Vite app, so reuse the React template
+    // pack for it before layering the `src-tauri/` backend on top.
+    let context = template_engine::build_context(&config)?;
+    let frontend = TemplateEngine::new("react")?;
+    frontend.render_all(project_path, &context)?;
+
+    let backend = TemplateEngine::new("tauri")?;
+    backend.render_all(project_path, &context)?;
+
+    ci::generate_ci_workflow(project_path, &config, ci::ProjectKind::Tauri)?;
+    project_file::save(project_path, &config)?;
+
+    println!("✅ Tauri project '{}' created successfully!", name);
+    println!("📁 Next steps:");
+    println!("   cd {}", name);
+    println!("   {}", package_manager.install_command());
+    println!("   {} dev", package_manager.exec_command("tauri"));
 
-fn generate_react_native_files(project_path: &Path, typescript: bool, _navigation: bool) -> Result<()> {
-    let ext = if typescript { "tsx" } else { "jsx" };
-    
-    let app_content = format!(r#"import React from 'react';
-import {{
-  SafeAreaView,
-  ScrollView,
-  StatusBar,
-  StyleSheet,
-  Text,
-  View,
-}} from 'react-native';
-
-function App(){} {{
-  return (
-    <SafeAreaView style={{styles.container}}>
-      <StatusBar barStyle="dark-content" />
-      <ScrollView contentInsetAdjustmentBehavior="automatic">
-        <View style={{styles.body}}>
-          <Text style={{styles.title}}>Welcome to React Native!</Text>
-          <Text style={{styles.subtitle}}>Your project is ready to go.</Text>
-        </View>
-      </ScrollView>
-    </SafeAreaView>
-  );
-}}
-
-const styles = StyleSheet.create({{
-  container: {{
-    flex: 1,
-  }},
-  body: {{
-    backgroundColor: '#fff',
-    flex: 1,
-    justifyContent: 'center',
-    alignItems: 'center',
-    padding: 20,
-  }},
-  title: {{
-    fontSize: 24,
-    fontWeight: 'bold',
-    marginBottom: 10,
-  }},
-  subtitle: {{
-    fontSize: 16,
-    color: '#666',
-  }},
-}});
-
-export default App;"#, if typescript { ": React.FC" } else { "" });
-    
-    fs::write(project_path.join(format!("src/App.{}", ext)), app_content)?;
-    
     Ok(())
 }
 
@@ -491,6 +482,7 @@ fn list_templates() {
     println!("  react         - React web application");
     println!("  react-native  - React Native mobile application");
     println!("  rust          - Rust application or library");
+    println!("  tauri         - Tauri desktop app (Rust + React)");
     println!();
     println!("Use --help with any command for more options.");
 }
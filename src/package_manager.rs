@@ -0,0 +1,104 @@
+// The Node package manager a scaffolded project should use. Threaded through
+// `ProjectConfig` so every generator (and the `--package-manager` flag that
+// feeds it) agrees on install/run commands, the `packageManager` field, and
+// which lockfiles to keep out of git.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lowercase")]
+pub enum PackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+}
+
+impl Default for PackageManager {
+    fn default() -> Self {
+        PackageManager::Npm
+    }
+}
+
+impl std::fmt::Display for PackageManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PackageManager {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Yarn => "yarn",
+        }
+    }
+
+    pub fn install_command(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm install",
+            PackageManager::Pnpm => "pnpm install",
+            PackageManager::Yarn => "yarn install",
+        }
+    }
+
+    /// How to invoke an npm script with this package manager, accounting for
+    /// the handful of scripts npm runs bare (`npm start`, not `npm run start`).
+    pub fn run_command(&self, script: &str) -> String {
+        match self {
+            PackageManager::Npm => {
+                if matches!(script, "start" | "test" | "install") {
+                    format!("npm {}", script)
+                } else {
+                    format!("npm run {}", script)
+                }
+            }
+            PackageManager::Pnpm => format!("pnpm {}", script),
+            PackageManager::Yarn => format!("yarn {}", script),
+        }
+    }
+
+    /// One-off binary execution, e.g. running `react-native` without adding
+    /// it as a dependency script.
+    pub fn exec_command(&self, bin: &str) -> String {
+        match self {
+            PackageManager::Npm => format!("npx {}", bin),
+            PackageManager::Pnpm => format!("pnpm dlx {}", bin),
+            PackageManager::Yarn => format!("yarn {}", bin),
+        }
+    }
+
+    /// Value for the `packageManager` field Corepack reads from package.json.
+    pub fn package_json_field(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm@10.2.4",
+            PackageManager::Pnpm => "pnpm@8.10.0",
+            PackageManager::Yarn => "yarn@3.6.4",
+        }
+    }
+
+    pub fn lockfile_name(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "package-lock.json",
+            PackageManager::Pnpm => "pnpm-lock.yaml",
+            PackageManager::Yarn => "yarn.lock",
+        }
+    }
+
+    /// Lockfiles of the *other* package managers, so the generated
+    /// `.gitignore` doesn't end up tracking whichever ones this project
+    /// doesn't use.
+    pub fn other_lockfiles(&self) -> Vec<&'static str> {
+        [
+            PackageManager::Npm,
+            PackageManager::Pnpm,
+            PackageManager::Yarn,
+        ]
+        .into_iter()
+        .filter(|pm| pm != self)
+        .map(|pm| pm.lockfile_name())
+        .collect()
+    }
+}
@@ -0,0 +1,274 @@
+// Persistence for the resolved `ProjectConfig`. Every generator writes one
+// of these into the project it scaffolds so `scaffold add`/`scaffold
+// regenerate` can read back what was chosen and apply new features later
+// without re-asking the user or clobbering files they've since edited.
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::ci::{self, ProjectKind};
+use crate::template_engine::{self, TemplateEngine};
+use crate::ProjectConfig;
+
+const CONFIG_FILE: &str = "scaffold.toml";
+
+/// Write `config` to `scaffold.toml` in `project_path`, overwriting any
+/// previous copy.
+pub fn save(project_path: &Path, config: &ProjectConfig) -> Result<()> {
+    let toml = toml::to_string_pretty(config).context("failed to serialize project config")?;
+    fs::write(project_path.join(CONFIG_FILE), toml)?;
+    Ok(())
+}
+
+/// Read back the `scaffold.toml` left in `project_path` by a previous
+/// `scaffold` invocation.
+pub fn load(project_path: &Path) -> Result<ProjectConfig> {
+    let path = project_path.join(CONFIG_FILE);
+    let toml = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "no {} found in {} — was this project created by scaffold?",
+            CONFIG_FILE,
+            project_path.display()
+        )
+    })?;
+    toml::from_str(&toml).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// A feature that can be bolted onto an already-scaffolded project via
+/// `scaffold add`, after the fact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum Feature {
+    Testing,
+    Storybook,
+    Tailwind,
+    E2e,
+    Navigation,
+}
+
+impl Feature {
+    fn enable_on(self, config: &mut ProjectConfig) -> Result<()> {
+        match (self, config.framework) {
+            (Feature::Testing, ProjectKind::React) => config.testing = true,
+            (Feature::Storybook, ProjectKind::React) => config.storybook = true,
+            (Feature::Tailwind, ProjectKind::React) => config.tailwind = true,
+            (Feature::E2e, ProjectKind::React) => config.e2e = true,
+            (Feature::Navigation, ProjectKind::ReactNative) => config.navigation = true,
+            (feature, framework) => {
+                anyhow::bail!("{:?} isn't available on a {:?} project", feature, framework)
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Enable `feature` on the project at `project_path`, render whatever files
+/// it needs (skipping any that already exist), and patch the feature's
+/// footprint into files the project already has (`package.json` scripts
+/// and deps, the Tailwind directives in `index.css`) so the feature is
+/// actually usable rather than just recorded in `scaffold.toml`.
+///
+/// `scaffold.toml` is saved *before* rendering, not after: every step past
+/// this point (`render_missing`, `patch_existing_files`) is idempotent, so
+/// if one of them fails partway, the recorded config still matches user
+/// intent and a follow-up `scaffold regenerate` picks up where it left off
+/// instead of the feature being silently half-applied and forgotten.
+pub fn add_feature(project_path: &Path, feature: Feature) -> Result<()> {
+    let mut config = load(project_path)?;
+    feature.enable_on(&mut config)?;
+    save(project_path, &config)?;
+    render_missing(project_path, &config)?;
+    patch_existing_files(project_path, &config)?;
+    ci::generate_ci_workflow(project_path, &config, config.framework)?;
+    Ok(())
+}
+
+/// Re-render the project at `project_path` from its saved config, creating
+/// any files the template pack produces that are missing (for example after
+/// a template update) without touching ones that already exist, and merging
+/// the current config's footprint into files the project already has.
+pub fn regenerate(project_path: &Path) -> Result<()> {
+    let config = load(project_path)?;
+    render_missing(project_path, &config)?;
+    patch_existing_files(project_path, &config)?;
+    ci::generate_ci_workflow(project_path, &config, config.framework)?;
+    Ok(())
+}
+
+fn render_missing(project_path: &Path, config: &ProjectConfig) -> Result<()> {
+    let context = template_engine::build_context(config)?;
+
+    match config.framework {
+        ProjectKind::React => {
+            TemplateEngine::new("react")?.render_missing(project_path, &context)?;
+        }
+        ProjectKind::ReactNative => {
+            TemplateEngine::new("react-native")?.render_missing(project_path, &context)?;
+        }
+        ProjectKind::Tauri => {
+            TemplateEngine::new("react")?.render_missing(project_path, &context)?;
+            TemplateEngine::new("tauri")?.render_missing(project_path, &context)?;
+        }
+        // Rust projects are scaffolded with `cargo new`, not a template
+        // pack, so there's nothing to re-render.
+        ProjectKind::Rust => {}
+    }
+
+    Ok(())
+}
+
+/// Bring files the project already has up to date with `config`, for
+/// footprints `render_missing`'s skip-if-exists rendering can't reach:
+/// `package.json`'s scripts/deps, and the Tailwind directives in
+/// `index.css`.
+fn patch_existing_files(project_path: &Path, config: &ProjectConfig) -> Result<()> {
+    let context = template_engine::build_context(config)?;
+
+    match config.framework {
+        ProjectKind::React | ProjectKind::Tauri => {
+            merge_package_json(project_path, "react", &context)?;
+            patch_tailwind_directives(project_path, &context)?;
+        }
+        ProjectKind::ReactNative => {
+            merge_package_json(project_path, "react-native", &context)?;
+        }
+        ProjectKind::Rust => {}
+    }
+
+    Ok(())
+}
+
+/// Add whatever scripts/dependencies/devDependencies the `framework` pack's
+/// `package.json.hbs` would now produce but the project's `package.json` is
+/// still missing, without touching keys the project (or the user) already
+/// has.
+///
+/// This edits the file as text rather than parsing it into a `Value` and
+/// re-serializing: `serde_json` sorts object keys alphabetically unless
+/// `preserve_order` is enabled, which would silently reshuffle every
+/// `package.json` this touches. Splicing new entries into the relevant
+/// object in place leaves everything else in the file untouched.
+fn merge_package_json(project_path: &Path, framework: &str, context: &Value) -> Result<()> {
+    let path = project_path.join("package.json");
+    let Ok(existing) = fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let existing_json: Value = serde_json::from_str(&existing)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+
+    let rendered = TemplateEngine::new(framework)?.render_named("package.json.hbs", context)?;
+    let incoming: Value =
+        serde_json::from_str(&rendered).context("failed to parse rendered package.json template")?;
+
+    let mut patched = existing.clone();
+    for object_key in ["scripts", "dependencies", "devDependencies"] {
+        let missing = missing_entries(&existing_json, &incoming, object_key);
+        if !missing.is_empty() {
+            patched = insert_json_entries(&patched, object_key, &missing)
+                .with_context(|| format!("failed to add {} to {}", object_key, path.display()))?;
+        }
+    }
+
+    if patched != existing {
+        fs::write(&path, patched)?;
+    }
+    Ok(())
+}
+
+/// Entries present in `incoming[object_key]` but absent from
+/// `existing[object_key]`, sorted by key (the parsed `Value` keeps object
+/// keys in alphabetical order, not in the template's order).
+fn missing_entries(existing: &Value, incoming: &Value, object_key: &str) -> Vec<(String, Value)> {
+    let Some(incoming_map) = incoming.get(object_key).and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let existing_map = existing.get(object_key).and_then(Value::as_object);
+
+    incoming_map
+        .iter()
+        .filter(|(key, _)| !existing_map.is_some_and(|m| m.contains_key(*key)))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+/// Splice `entries` into the JSON object named `object_key` in `text`,
+/// appended just before its closing brace. The whitespace between that
+/// object's last entry and its closing brace becomes a newline plus two
+/// spaces; the rest of `text` is left byte-for-byte unchanged.
+fn insert_json_entries(text: &str, object_key: &str, entries: &[(String, Value)]) -> Result<String> {
+    let key_pos = text
+        .find(&format!("\"{object_key}\""))
+        .with_context(|| format!("package.json has no \"{object_key}\" field"))?;
+    let brace_pos = text[key_pos..]
+        .find('{')
+        .map(|i| key_pos + i)
+        .with_context(|| format!("malformed \"{object_key}\" field in package.json"))?;
+
+    let mut depth = 0i32;
+    let mut close_pos = None;
+    for (offset, ch) in text[brace_pos..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    close_pos = Some(brace_pos + offset);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let close_pos =
+        close_pos.with_context(|| format!("unbalanced braces in \"{object_key}\" field in package.json"))?;
+
+    let body = &text[brace_pos + 1..close_pos];
+    let has_entries = !body.trim().is_empty();
+    let content_end = brace_pos + 1 + body.trim_end().len();
+
+    let mut insertion = String::new();
+    if has_entries {
+        insertion.push(',');
+    }
+    for (key, value) in entries {
+        insertion.push_str("\n    \"");
+        insertion.push_str(key);
+        insertion.push_str("\": ");
+        insertion.push_str(&serde_json::to_string(value)?);
+        insertion.push(',');
+    }
+    insertion.pop(); // drop the trailing comma after the last new entry
+    insertion.push('\n');
+    insertion.push_str("  ");
+
+    let mut out = String::with_capacity(text.len() + insertion.len());
+    out.push_str(&text[..content_end]);
+    out.push_str(&insertion);
+    out.push_str(&text[close_pos..]);
+    Ok(out)
+}
+
+/// Prepend the `@tailwind` directives to an existing `src/index.css` once
+/// `tailwind` is enabled, if they aren't there already.
+fn patch_tailwind_directives(project_path: &Path, context: &Value) -> Result<()> {
+    let enabled = context.get("tailwind").and_then(Value::as_bool).unwrap_or(false);
+    if !enabled {
+        return Ok(());
+    }
+
+    let path = project_path.join("src/index.css");
+    let Ok(existing) = fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    if existing.contains("@tailwind") {
+        return Ok(());
+    }
+
+    let directives = "@tailwind base;\n@tailwind components;\n@tailwind utilities;\n\n";
+    fs::write(&path, format!("{directives}{existing}"))?;
+    Ok(())
+}
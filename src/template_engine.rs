@@ -0,0 +1,174 @@
+// Template packs: a `templates/<framework>` directory (bundled with the
+// crate, or overridden under `~/.config/scaffold/templates/<framework>`)
+// whose file tree *is* the generated project layout. File contents, and
+// the per-language parts of file names (`src/App.{{ext}}.hbs`), are
+// rendered through Handlebars. Whole-file conditionals can't use that same
+// `{{ }}` syntax in a path component (filesystems don't allow `/` in a
+// name, and handlebars can't parse a block helper that's split across
+// several path segments), so a pack gates a file or directory by prefixing
+// its name with `__if_<flag>__`, e.g. `__if_typescript__tsconfig.json.hbs`.
+// The prefix is stripped and resolved against `context` in Rust, not by
+// Handlebars.
+
+use anyhow::{Context, Result};
+use handlebars::Handlebars;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::ProjectConfig;
+
+pub struct TemplateEngine {
+    handlebars: Handlebars<'static>,
+    root: PathBuf,
+}
+
+impl TemplateEngine {
+    /// Resolve the template pack for `framework`, preferring a
+    /// user-provided override over the bundled default.
+    pub fn new(framework: &str) -> Result<Self> {
+        let root = Self::template_root(framework)?;
+        let mut handlebars = Handlebars::new();
+        handlebars.set_strict_mode(false);
+        Ok(Self { handlebars, root })
+    }
+
+    fn template_root(framework: &str) -> Result<PathBuf> {
+        if let Some(config_dir) = user_config_dir() {
+            let user_pack = config_dir.join("scaffold/templates").join(framework);
+            if user_pack.is_dir() {
+                return Ok(user_pack);
+            }
+        }
+
+        let bundled = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates")
+            .join(framework);
+        if bundled.is_dir() {
+            return Ok(bundled);
+        }
+
+        anyhow::bail!("no template pack found for framework '{}'", framework)
+    }
+
+    /// Render every file in the template pack into `dest`, expanding both
+    /// the file path and its contents against `context`. A file (or
+    /// directory) behind an `__if_<flag>__` prefix is skipped unless `flag`
+    /// is true in `context`, and one whose rendered name is empty is
+    /// skipped as well.
+    pub fn render_all(&self, dest: &Path, context: &Value) -> Result<()> {
+        self.render_dir(&self.root.clone(), dest, context, true)
+    }
+
+    /// Like [`render_all`](Self::render_all), but never overwrites a file
+    /// that already exists at the destination. Used to apply a newly
+    /// enabled feature to an existing project without clobbering edits the
+    /// user has made to files the pack also generates.
+    pub fn render_missing(&self, dest: &Path, context: &Value) -> Result<()> {
+        self.render_dir(&self.root.clone(), dest, context, false)
+    }
+
+    fn render_dir(&self, src: &Path, dest: &Path, context: &Value, overwrite: bool) -> Result<()> {
+        for entry in fs::read_dir(src).with_context(|| format!("failed to read {}", src.display()))? {
+            let entry = entry?;
+            let path = entry.path();
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            let Some(gated_name) = Self::resolve_gate(&file_name, context) else {
+                continue;
+            };
+
+            let rendered_name = self
+                .handlebars
+                .render_template(gated_name, context)
+                .with_context(|| format!("failed to render file name '{}'", file_name))?;
+            if rendered_name.trim().is_empty() {
+                continue;
+            }
+
+            if path.is_dir() {
+                self.render_dir(&path, &dest.join(rendered_name), context, overwrite)?;
+            } else if rendered_name == ".gitkeep" {
+                fs::create_dir_all(dest)?;
+            } else {
+                let out_name = rendered_name.strip_suffix(".hbs").unwrap_or(&rendered_name);
+                let out_path = dest.join(out_name);
+                if !overwrite && out_path.exists() {
+                    continue;
+                }
+
+                let template = fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read template {}", path.display()))?;
+                let rendered = self
+                    .handlebars
+                    .render_template(&template, context)
+                    .with_context(|| format!("failed to render {}", path.display()))?;
+
+                fs::create_dir_all(dest)?;
+                fs::write(out_path, rendered)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render a single template's contents by its un-gated path under the
+    /// pack root (e.g. `"package.json.hbs"`), without touching anything on
+    /// disk. Used to produce a reference rendering of a file that already
+    /// exists in a project, so its contents can be merged into rather than
+    /// skipped outright.
+    pub fn render_named(&self, relative_path: &str, context: &Value) -> Result<String> {
+        let path = self.root.join(relative_path);
+        let template = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read template {}", path.display()))?;
+        self.handlebars
+            .render_template(&template, context)
+            .with_context(|| format!("failed to render {}", path.display()))
+    }
+
+    /// Strip a leading `__if_<flag>__` gate from a raw (un-rendered) path
+    /// component, returning the rest of the name if `flag` is truthy in
+    /// `context`, or `None` if the whole entry should be skipped. A name
+    /// without the prefix always passes through unchanged.
+    fn resolve_gate<'a>(file_name: &'a str, context: &Value) -> Option<&'a str> {
+        let Some(rest) = file_name.strip_prefix(CONDITIONAL_PREFIX) else {
+            return Some(file_name);
+        };
+        let (flag, name) = rest.split_once("__")?;
+        let enabled = context
+            .get(flag)
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        enabled.then_some(name)
+    }
+}
+
+/// Marks a path component as conditional on a `ProjectConfig` flag, e.g.
+/// `__if_typescript__tsconfig.json.hbs`. Resolved by [`TemplateEngine::resolve_gate`]
+/// before the name is handed to Handlebars.
+const CONDITIONAL_PREFIX: &str = "__if_";
+
+/// Build the Handlebars render context from a `ProjectConfig`, adding the
+/// derived fields (like `ext`) that templates rely on but that aren't part
+/// of the persisted config itself.
+pub fn build_context(config: &ProjectConfig) -> Result<Value> {
+    let mut value = serde_json::to_value(config).context("failed to serialize project config")?;
+    if let Value::Object(ref mut map) = value {
+        let ext = if config.typescript { "tsx" } else { "jsx" };
+        map.insert("ext".to_string(), Value::String(ext.to_string()));
+
+        map.insert(
+            "package_manager_field".to_string(),
+            Value::String(config.package_manager.package_json_field().to_string()),
+        );
+        map.insert(
+            "other_lockfiles".to_string(),
+            serde_json::to_value(config.package_manager.other_lockfiles())?,
+        );
+    }
+    Ok(value)
+}
+
+fn user_config_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+}
@@ -0,0 +1,170 @@
+// Interactive prompt flow used whenever a subcommand is invoked without a
+// project name (including a bare `scaffold` with no subcommand at all).
+// Scripted/CI invocations always pass a name, so they never hit this module.
+
+use anyhow::Result;
+use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
+
+use crate::ci::CiProvider;
+use crate::package_manager::PackageManager;
+
+pub enum ProjectChoice {
+    React,
+    ReactNative,
+    Rust,
+    Tauri,
+}
+
+pub struct ReactAnswers {
+    pub name: String,
+    pub typescript: bool,
+    pub testing: bool,
+    pub package_manager: PackageManager,
+    pub storybook: bool,
+    pub tailwind: bool,
+    pub e2e: bool,
+    pub ci: Option<CiProvider>,
+}
+
+pub struct ReactNativeAnswers {
+    pub name: String,
+    pub typescript: bool,
+    pub navigation: bool,
+    pub package_manager: PackageManager,
+    pub ci: Option<CiProvider>,
+}
+
+pub struct RustAnswers {
+    pub name: String,
+    pub project_type: String,
+    pub ci: Option<CiProvider>,
+}
+
+pub struct TauriAnswers {
+    pub name: String,
+    pub typescript: bool,
+    pub package_manager: PackageManager,
+    pub ci: Option<CiProvider>,
+}
+
+/// Asked when `scaffold` is run with no subcommand at all.
+pub fn project_type_wizard() -> Result<ProjectChoice> {
+    let options = ["React", "React Native", "Rust", "Tauri"];
+    let choice = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("What would you like to scaffold?")
+        .items(&options)
+        .default(0)
+        .interact()?;
+
+    Ok(match choice {
+        0 => ProjectChoice::React,
+        1 => ProjectChoice::ReactNative,
+        2 => ProjectChoice::Rust,
+        _ => ProjectChoice::Tauri,
+    })
+}
+
+pub fn react_wizard() -> Result<ReactAnswers> {
+    let name: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Project name")
+        .interact_text()?;
+    let typescript = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Use TypeScript?")
+        .default(true)
+        .interact()?;
+    let testing = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Include testing setup (Vitest)?")
+        .default(false)
+        .interact()?;
+    let storybook = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Add Storybook?")
+        .default(false)
+        .interact()?;
+    let tailwind = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Add Tailwind CSS?")
+        .default(false)
+        .interact()?;
+    let e2e = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Add Playwright end-to-end tests?")
+        .default(false)
+        .interact()?;
+    let package_manager = package_manager_wizard()?;
+    let ci = ci_wizard()?;
+
+    Ok(ReactAnswers { name, typescript, testing, package_manager, storybook, tailwind, e2e, ci })
+}
+
+pub fn react_native_wizard() -> Result<ReactNativeAnswers> {
+    let name: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Project name")
+        .interact_text()?;
+    let typescript = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Use TypeScript?")
+        .default(true)
+        .interact()?;
+    let navigation = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Include navigation setup (React Navigation)?")
+        .default(false)
+        .interact()?;
+    let package_manager = package_manager_wizard()?;
+    let ci = ci_wizard()?;
+
+    Ok(ReactNativeAnswers { name, typescript, navigation, package_manager, ci })
+}
+
+fn package_manager_wizard() -> Result<PackageManager> {
+    let options = ["npm", "pnpm", "yarn"];
+    let choice = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Package manager")
+        .items(&options)
+        .default(0)
+        .interact()?;
+
+    Ok(match choice {
+        0 => PackageManager::Npm,
+        1 => PackageManager::Pnpm,
+        _ => PackageManager::Yarn,
+    })
+}
+
+fn ci_wizard() -> Result<Option<CiProvider>> {
+    let add_ci = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Generate a CI workflow (GitHub Actions)?")
+        .default(false)
+        .interact()?;
+
+    Ok(add_ci.then_some(CiProvider::Github))
+}
+
+pub fn rust_wizard() -> Result<RustAnswers> {
+    let name: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Project name")
+        .interact_text()?;
+    let project_types = ["binary", "library"];
+    let choice = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Project type")
+        .items(&project_types)
+        .default(0)
+        .interact()?;
+    let ci = ci_wizard()?;
+
+    Ok(RustAnswers {
+        name,
+        project_type: project_types[choice].to_string(),
+        ci,
+    })
+}
+
+pub fn tauri_wizard() -> Result<TauriAnswers> {
+    let name: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Project name")
+        .interact_text()?;
+    let typescript = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Use TypeScript?")
+        .default(true)
+        .interact()?;
+    let package_manager = package_manager_wizard()?;
+    let ci = ci_wizard()?;
+
+    Ok(TauriAnswers { name, typescript, package_manager, ci })
+}